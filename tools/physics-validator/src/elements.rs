@@ -0,0 +1,335 @@
+//! Classical (Keplerian) orbital elements and state-vector conversions.
+//!
+//! This module describes an orbit by its six classical elements and converts
+//! between that description and a Cartesian state `(position, velocity)` about a
+//! body of gravitational parameter `mu = G · M`, as poliastro's
+//! `Orbit.from_vectors` / `Orbit.from_classical` do. Angles are radians and
+//! lengths are whatever unit the caller uses for `mu` (SI metres throughout the
+//! rest of the crate).
+
+use std::error::Error;
+use std::fmt;
+
+use crate::vec3::{cross, dot, norm, scale, sub};
+
+/// Orbits closer than this (in eccentricity) to parabolic are rejected, since
+/// `a = −mu/(2ε)` diverges as `ε → 0`.
+const PARABOLIC_TOLERANCE: f64 = 1e-9;
+
+/// The six classical orbital elements.
+///
+/// All angles are in radians; `semi_major_axis` shares the length unit implied
+/// by `mu` in the conversion routines.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OrbitalElements {
+    /// Semi-major axis `a`.
+    pub semi_major_axis: f64,
+    /// Eccentricity `ecc` (0 for a circle, <1 for an ellipse).
+    pub eccentricity: f64,
+    /// Inclination `i` in radians, measured from the reference plane.
+    pub inclination: f64,
+    /// Right ascension of the ascending node `Ω` in radians.
+    pub raan: f64,
+    /// Argument of periapsis `ω` in radians.
+    pub arg_periapsis: f64,
+    /// True anomaly `ν` in radians.
+    pub true_anomaly: f64,
+}
+
+/// Errors returned by the element/state conversions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ElementsError {
+    /// The orbit is (near-)parabolic, so the semi-major axis is undefined.
+    ParabolicOrbit,
+    /// The inclination is outside the physical range `[0, π]`.
+    InvalidInclination,
+}
+
+impl fmt::Display for ElementsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ElementsError::ParabolicOrbit => {
+                write!(f, "parabolic orbit: semi-major axis is undefined for ecc ≈ 1")
+            }
+            ElementsError::InvalidInclination => {
+                write!(f, "inclination out of range: expected 0 ≤ i ≤ π")
+            }
+        }
+    }
+}
+
+impl Error for ElementsError {}
+
+/// `acos` guarded against arguments that stray just outside `[-1, 1]` through
+/// floating-point round-off.
+fn safe_acos(x: f64) -> f64 {
+    x.clamp(-1.0, 1.0).acos()
+}
+
+/// Convert a Cartesian state to classical orbital elements (`rv2coe`).
+///
+/// # Arguments
+/// * `mu` - Gravitational parameter `G · M` of the central body
+/// * `position` - Position vector `r` relative to the central body
+/// * `velocity` - Velocity vector `v` relative to the central body
+///
+/// # Returns
+/// The classical elements, or [`ElementsError::ParabolicOrbit`] when the orbit
+/// is too close to parabolic for the semi-major axis to be defined.
+///
+/// # Example
+/// ```
+/// use physics_validator::elements::rv2coe;
+/// use physics_validator::{EARTH_MASS, G};
+///
+/// let mu = G * EARTH_MASS;
+/// let r = [7.0e6, 0.0, 0.0];
+/// let v = [0.0, (mu / 7.0e6).sqrt(), 0.0];
+/// let coe = rv2coe(mu, r, v).unwrap();
+///
+/// // A purely tangential velocity at |r| gives a circular orbit.
+/// assert!(coe.eccentricity < 1e-9);
+/// assert!((coe.semi_major_axis - 7.0e6).abs() / 7.0e6 < 1e-9);
+/// ```
+pub fn rv2coe(
+    mu: f64,
+    position: [f64; 3],
+    velocity: [f64; 3],
+) -> Result<OrbitalElements, ElementsError> {
+    let r = position;
+    let v = velocity;
+    let r_mag = norm(r);
+    let v_mag = norm(v);
+
+    // Specific angular momentum and node vector.
+    let h = cross(r, v);
+    let h_mag = norm(h);
+    let n = cross([0.0, 0.0, 1.0], h);
+    let n_mag = norm(n);
+
+    // Eccentricity vector.
+    let e_vec = scale(
+        sub(scale(r, v_mag * v_mag - mu / r_mag), scale(v, dot(r, v))),
+        1.0 / mu,
+    );
+    let ecc = norm(e_vec);
+    if (ecc - 1.0).abs() < PARABOLIC_TOLERANCE {
+        return Err(ElementsError::ParabolicOrbit);
+    }
+
+    // Specific orbital energy and semi-major axis.
+    let energy = v_mag * v_mag / 2.0 - mu / r_mag;
+    let semi_major_axis = -mu / (2.0 * energy);
+
+    let inclination = safe_acos(h[2] / h_mag);
+
+    // RAAN, argument of periapsis and true anomaly, with quadrant fixes. The
+    // node- and eccentricity-dependent angles are undefined for equatorial and
+    // circular orbits respectively; in those degenerate cases we fall back to
+    // zero rather than producing a NaN.
+    let raan = if n_mag > 0.0 {
+        let omega = safe_acos(n[0] / n_mag);
+        if n[1] < 0.0 {
+            2.0 * std::f64::consts::PI - omega
+        } else {
+            omega
+        }
+    } else {
+        0.0
+    };
+
+    let arg_periapsis = if n_mag > 0.0 && ecc > 0.0 {
+        let argp = safe_acos(dot(n, e_vec) / (n_mag * ecc));
+        if e_vec[2] < 0.0 {
+            2.0 * std::f64::consts::PI - argp
+        } else {
+            argp
+        }
+    } else {
+        0.0
+    };
+
+    let true_anomaly = if ecc > 0.0 {
+        let nu = safe_acos(dot(e_vec, r) / (ecc * r_mag));
+        if dot(r, v) < 0.0 {
+            2.0 * std::f64::consts::PI - nu
+        } else {
+            nu
+        }
+    } else {
+        0.0
+    };
+
+    Ok(OrbitalElements {
+        semi_major_axis,
+        eccentricity: ecc,
+        inclination,
+        raan,
+        arg_periapsis,
+        true_anomaly,
+    })
+}
+
+/// Convert classical orbital elements to a Cartesian state (`coe2rv`).
+///
+/// The position and velocity are first assembled in the perifocal frame and
+/// then rotated into the reference frame by the 3-1-3 Euler sequence
+/// `(Ω, i, ω)`.
+///
+/// # Arguments
+/// * `mu` - Gravitational parameter `G · M` of the central body
+/// * `elements` - The classical elements to convert
+///
+/// # Returns
+/// The `(position, velocity)` pair, or an [`ElementsError`] when the orbit is
+/// near-parabolic or the inclination is outside `[0, π]`.
+///
+/// # Example
+/// ```
+/// use physics_validator::elements::{coe2rv, rv2coe, OrbitalElements};
+/// use physics_validator::{EARTH_MASS, G};
+///
+/// let mu = G * EARTH_MASS;
+/// let coe = OrbitalElements {
+///     semi_major_axis: 7.0e6,
+///     eccentricity: 0.01,
+///     inclination: 0.9,
+///     raan: 1.2,
+///     arg_periapsis: 0.3,
+///     true_anomaly: 0.5,
+/// };
+/// let (r, v) = coe2rv(mu, coe).unwrap();
+///
+/// // The round trip recovers the original elements.
+/// let back = rv2coe(mu, r, v).unwrap();
+/// assert!((back.eccentricity - coe.eccentricity).abs() < 1e-9);
+/// ```
+pub fn coe2rv(
+    mu: f64,
+    elements: OrbitalElements,
+) -> Result<([f64; 3], [f64; 3]), ElementsError> {
+    let OrbitalElements {
+        semi_major_axis: a,
+        eccentricity: ecc,
+        inclination: i,
+        raan,
+        arg_periapsis: argp,
+        true_anomaly: nu,
+    } = elements;
+
+    if (ecc - 1.0).abs() < PARABOLIC_TOLERANCE {
+        return Err(ElementsError::ParabolicOrbit);
+    }
+    if !(0.0..=std::f64::consts::PI).contains(&i) {
+        return Err(ElementsError::InvalidInclination);
+    }
+
+    // Semi-latus rectum and radius at the requested true anomaly.
+    let p = a * (1.0 - ecc * ecc);
+    let r_mag = p / (1.0 + ecc * nu.cos());
+
+    // State in the perifocal frame.
+    let r_pf = [r_mag * nu.cos(), r_mag * nu.sin(), 0.0];
+    let sqrt_mu_p = (mu / p).sqrt();
+    let v_pf = [-sqrt_mu_p * nu.sin(), sqrt_mu_p * (ecc + nu.cos()), 0.0];
+
+    Ok((rotate_perifocal(r_pf, raan, i, argp), rotate_perifocal(v_pf, raan, i, argp)))
+}
+
+/// Rotate a perifocal-frame vector into the reference frame using the 3-1-3
+/// sequence `(Ω, i, ω)`.
+fn rotate_perifocal(vec: [f64; 3], raan: f64, i: f64, argp: f64) -> [f64; 3] {
+    let (co, so) = (raan.cos(), raan.sin());
+    let (ci, si) = (i.cos(), i.sin());
+    let (cw, sw) = (argp.cos(), argp.sin());
+
+    let r11 = co * cw - so * sw * ci;
+    let r12 = -co * sw - so * cw * ci;
+    let r13 = so * si;
+    let r21 = so * cw + co * sw * ci;
+    let r22 = -so * sw + co * cw * ci;
+    let r23 = -co * si;
+    let r31 = sw * si;
+    let r32 = cw * si;
+    let r33 = ci;
+
+    [
+        r11 * vec[0] + r12 * vec[1] + r13 * vec[2],
+        r21 * vec[0] + r22 * vec[1] + r23 * vec[2],
+        r31 * vec[0] + r32 * vec[1] + r33 * vec[2],
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{EARTH_MASS, G};
+
+    const MU: f64 = G * EARTH_MASS;
+    const EARTH_RADIUS_PLUS_LEO: f64 = 6.771e6;
+
+    fn relative_error(actual: f64, expected: f64) -> f64 {
+        ((actual - expected) / expected).abs()
+    }
+
+    #[test]
+    fn test_circular_equatorial_roundtrip() {
+        // Tangential velocity at |r| in the equatorial plane is a circle.
+        let r_mag = EARTH_RADIUS_PLUS_LEO;
+        let r = [r_mag, 0.0, 0.0];
+        let v = [0.0, (MU / r_mag).sqrt(), 0.0];
+
+        let coe = rv2coe(MU, r, v).unwrap();
+        assert!(coe.eccentricity < 1e-9);
+        assert!(relative_error(coe.semi_major_axis, r_mag) < 1e-9);
+        assert!(coe.inclination.abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_elements_to_state_roundtrip() {
+        let coe = OrbitalElements {
+            semi_major_axis: 8.0e6,
+            eccentricity: 0.1,
+            inclination: 0.5,
+            raan: 1.0,
+            arg_periapsis: 0.4,
+            true_anomaly: 0.7,
+        };
+
+        let (r, v) = coe2rv(MU, coe).unwrap();
+        let back = rv2coe(MU, r, v).unwrap();
+
+        assert!(relative_error(back.semi_major_axis, coe.semi_major_axis) < 1e-9);
+        assert!((back.eccentricity - coe.eccentricity).abs() < 1e-9);
+        assert!((back.inclination - coe.inclination).abs() < 1e-9);
+        assert!((back.raan - coe.raan).abs() < 1e-9);
+        assert!((back.arg_periapsis - coe.arg_periapsis).abs() < 1e-9);
+        assert!((back.true_anomaly - coe.true_anomaly).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_parabolic_is_rejected() {
+        let coe = OrbitalElements {
+            semi_major_axis: 1.0e7,
+            eccentricity: 1.0,
+            inclination: 0.3,
+            raan: 0.0,
+            arg_periapsis: 0.0,
+            true_anomaly: 0.0,
+        };
+        assert_eq!(coe2rv(MU, coe), Err(ElementsError::ParabolicOrbit));
+    }
+
+    #[test]
+    fn test_invalid_inclination_is_rejected() {
+        let coe = OrbitalElements {
+            semi_major_axis: 1.0e7,
+            eccentricity: 0.1,
+            inclination: 4.0,
+            raan: 0.0,
+            arg_periapsis: 0.0,
+            true_anomaly: 0.0,
+        };
+        assert_eq!(coe2rv(MU, coe), Err(ElementsError::InvalidInclination));
+    }
+}
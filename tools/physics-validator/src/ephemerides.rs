@@ -0,0 +1,191 @@
+//! Low-precision Sun and Moon ephemerides and third-body gravity.
+//!
+//! [`sun_position`] and [`moon_position`] return geocentric positions in the
+//! EME2000/J2000 equatorial frame using the analytic series of Montenbruck &
+//! Gill (*Satellite Orbits*), accurate to a fraction of a degree — enough to
+//! drive the third-body perturbation [`third_body_acceleration`], which uses
+//! Battin's differenced form to avoid loss of significance.
+
+use crate::propagation::Accel;
+use crate::vec3::{norm, scale, sub};
+
+/// Modified Julian Date of the J2000.0 epoch (2000-01-01 12:00 TT).
+pub const MJD2000: f64 = 51544.5;
+
+/// Gravitational parameter of the Sun, in m³/s².
+pub const MU_SUN: f64 = 1.32712440018e20;
+
+/// Gravitational parameter of the Moon, in m³/s².
+pub const MU_MOON: f64 = 4.9028e12;
+
+/// Obliquity of the ecliptic at J2000, in radians.
+const OBLIQUITY: f64 = 23.43929111 * std::f64::consts::PI / 180.0;
+
+/// Fractional part, mapping into `[0, 1)`.
+fn frac(x: f64) -> f64 {
+    x - x.floor()
+}
+
+/// Rotate an ecliptic vector into the equatorial frame (`R_x(−ε)`).
+fn ecliptic_to_equatorial(v: [f64; 3]) -> [f64; 3] {
+    let (c, s) = (OBLIQUITY.cos(), OBLIQUITY.sin());
+    [v[0], c * v[1] - s * v[2], s * v[1] + c * v[2]]
+}
+
+/// Geocentric position of the Sun in the EME2000/J2000 frame.
+///
+/// # Arguments
+/// * `mjd` - Modified Julian Date (TT)
+///
+/// # Returns
+/// The Sun's position vector in metres.
+pub fn sun_position(mjd: f64) -> [f64; 3] {
+    use std::f64::consts::PI;
+    let t = (mjd - MJD2000) / 36525.0;
+
+    // Mean anomaly and ecliptic longitude (M&G low-precision "MiniSun").
+    let m = 2.0 * PI * frac(0.9931267 + 99.9973583 * t);
+    let l = 2.0 * PI
+        * frac(0.7859444 + m / (2.0 * PI) + (6892.0 * m.sin() + 72.0 * (2.0 * m).sin()) / 1_296.0e3);
+    let r = 149.619e9 - 2.499e9 * m.cos() - 0.021e9 * (2.0 * m).cos();
+
+    let ecl = [r * l.cos(), r * l.sin(), 0.0];
+    ecliptic_to_equatorial(ecl)
+}
+
+/// Geocentric position of the Moon in the EME2000/J2000 frame.
+///
+/// # Arguments
+/// * `mjd` - Modified Julian Date (TT)
+///
+/// # Returns
+/// The Moon's position vector in metres.
+pub fn moon_position(mjd: f64) -> [f64; 3] {
+    use std::f64::consts::PI;
+    let t = (mjd - MJD2000) / 36525.0;
+
+    // Fundamental arguments (revolutions / radians).
+    let l0 = frac(0.606433 + 1336.851344 * t); // mean longitude (rev)
+    let l = 2.0 * PI * frac(0.374897 + 1325.552410 * t); // Moon mean anomaly
+    let lp = 2.0 * PI * frac(0.993133 + 99.997361 * t); // Sun mean anomaly
+    let d = 2.0 * PI * frac(0.827361 + 1236.853086 * t); // mean elongation
+    let f = 2.0 * PI * frac(0.259086 + 1342.227825 * t); // argument of latitude
+
+    // Longitude perturbations (arcseconds).
+    let d_lambda = 22640.0 * l.sin() - 4586.0 * (l - 2.0 * d).sin()
+        + 2370.0 * (2.0 * d).sin()
+        + 769.0 * (2.0 * l).sin()
+        - 668.0 * lp.sin()
+        - 412.0 * (2.0 * f).sin()
+        - 212.0 * (2.0 * l - 2.0 * d).sin()
+        - 206.0 * (l + lp - 2.0 * d).sin()
+        + 192.0 * (l + 2.0 * d).sin()
+        - 165.0 * (lp - 2.0 * d).sin()
+        + 148.0 * (l - lp).sin()
+        - 125.0 * d.sin()
+        - 110.0 * (l + lp).sin()
+        - 55.0 * (2.0 * f - 2.0 * d).sin();
+
+    let lambda = 2.0 * PI * frac(l0 + d_lambda / 1_296.0e3);
+
+    // Latitude (arcseconds), via the argument of latitude S.
+    let s = f + (d_lambda + 412.0 * (2.0 * f).sin() + 541.0 * lp.sin()) / 206_265.0;
+    let h = f - 2.0 * d;
+    let n = -526.0 * h.sin() + 44.0 * (l + h).sin() - 31.0 * (-l + h).sin()
+        - 23.0 * (lp + h).sin()
+        + 11.0 * (-lp + h).sin()
+        - 25.0 * (-2.0 * l + f).sin()
+        + 21.0 * (-l + f).sin();
+    let beta = (18520.0 * s.sin() + n) / 206_265.0;
+
+    // Geocentric distance (M&G use a fixed mean value of 385000 km).
+    let r = 385_000.0e3;
+    let cb = beta.cos();
+    let ecl = [r * cb * lambda.cos(), r * cb * lambda.sin(), r * beta.sin()];
+    ecliptic_to_equatorial(ecl)
+}
+
+/// Third-body gravitational acceleration on a satellite.
+///
+/// Uses Battin's differenced form
+///
+/// ```text
+/// a = mu_body · [ (r_body − r_sat)/|r_body − r_sat|³ − r_body/|r_body|³ ]
+/// ```
+///
+/// which avoids the cancellation error of differencing two large, nearly equal
+/// direct terms.
+///
+/// # Arguments
+/// * `r_sat` - Geocentric satellite position (m)
+/// * `r_body` - Geocentric position of the perturbing body (m)
+/// * `mu_body` - Gravitational parameter of the perturbing body (m³/s²)
+///
+/// # Returns
+/// The third-body perturbing acceleration in m/s²
+pub fn third_body_acceleration(r_sat: [f64; 3], r_body: [f64; 3], mu_body: f64) -> [f64; 3] {
+    let d = sub(r_body, r_sat);
+    let d_mag = norm(d);
+    let rb_mag = norm(r_body);
+
+    let relative = scale(d, 1.0 / (d_mag * d_mag * d_mag));
+    let direct = scale(r_body, 1.0 / (rb_mag * rb_mag * rb_mag));
+    scale(sub(relative, direct), mu_body)
+}
+
+/// Third-body perturbation as an [`Accel`] for the Cowell propagator.
+///
+/// The perturbing body's position is held fixed at [`Self::r_body`] over the
+/// integration arc, a reasonable approximation for the short spans typical of a
+/// single propagation; recompute it from [`sun_position`]/[`moon_position`]
+/// between arcs for longer runs.
+pub struct ThirdBody {
+    /// Geocentric position of the perturbing body (m).
+    pub r_body: [f64; 3],
+    /// Gravitational parameter of the perturbing body (m³/s²).
+    pub mu_body: f64,
+}
+
+impl Accel for ThirdBody {
+    fn acceleration(&self, _t: f64, r: [f64; 3], _v: [f64; 3]) -> [f64; 3] {
+        third_body_acceleration(r, self.r_body, self.mu_body)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// One astronomical unit in metres.
+    const AU: f64 = 1.495_978_707e11;
+
+    #[test]
+    fn test_sun_distance_is_about_one_au() {
+        let r = norm(sun_position(MJD2000));
+        // The Earth-Sun distance stays within a few percent of 1 AU.
+        assert!((r - AU).abs() / AU < 0.03, "sun distance {} m", r);
+    }
+
+    #[test]
+    fn test_moon_distance_is_in_range() {
+        let r = norm(moon_position(MJD2000));
+        // Lunar distance ranges roughly 356000–407000 km.
+        assert!((356_000.0e3..=407_000.0e3).contains(&r), "moon distance {} m", r);
+    }
+
+    #[test]
+    fn test_third_body_vanishes_at_origin() {
+        // A satellite at the geocentre feels no differential third-body pull.
+        let a = third_body_acceleration([0.0, 0.0, 0.0], [1.0e11, 0.0, 0.0], MU_SUN);
+        assert!(norm(a) < 1e-30);
+    }
+
+    #[test]
+    fn test_third_body_points_toward_body() {
+        // Offsetting the satellite toward the body increases the net pull along
+        // the body direction.
+        let r_body = [1.0e9, 0.0, 0.0];
+        let a = third_body_acceleration([1.0e6, 0.0, 0.0], r_body, MU_MOON);
+        assert!(a[0] > 0.0, "expected pull toward +x body, got {:?}", a);
+    }
+}
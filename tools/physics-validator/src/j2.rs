@@ -0,0 +1,120 @@
+//! J2 oblateness perturbation.
+//!
+//! The leading non-spherical term of a body's gravity field is the J2 zonal
+//! harmonic, which causes the familiar secular regression of the node and
+//! advance of periapsis. [`j2_acceleration`] gives the perturbing acceleration
+//! and [`J2Perturbation`] wraps it as an [`Accel`] for the Cowell propagator.
+
+use crate::propagation::Accel;
+use crate::vec3::norm;
+
+/// J2 perturbing acceleration for a point at position `r`.
+///
+/// With `r = |r|`, each component is
+///
+/// ```text
+/// a_i = −(3/2) · J2 · (mu/r²) · (R/r)² · (x_i/r) · f_i
+/// ```
+///
+/// where `f = 1 − 5(z/r)²` for the x and y components and `f = 3 − 5(z/r)²`
+/// for z.
+///
+/// # Arguments
+/// * `r` - Position vector relative to the body centre (m)
+/// * `mu` - Gravitational parameter `G · M` of the body
+/// * `j2` - Dimensionless J2 coefficient (e.g. [`EARTH_J2`](crate::EARTH_J2))
+/// * `r_body` - Equatorial radius of the body (m)
+///
+/// # Returns
+/// The perturbing acceleration in m/s²
+pub fn j2_acceleration(r: [f64; 3], mu: f64, j2: f64, r_body: f64) -> [f64; 3] {
+    let r_mag = norm(r);
+    let z_ratio_sq = (r[2] / r_mag) * (r[2] / r_mag);
+
+    let factor = -1.5 * j2 * (mu / (r_mag * r_mag)) * (r_body / r_mag) * (r_body / r_mag);
+    let fxy = 1.0 - 5.0 * z_ratio_sq;
+    let fz = 3.0 - 5.0 * z_ratio_sq;
+
+    [
+        factor * (r[0] / r_mag) * fxy,
+        factor * (r[1] / r_mag) * fxy,
+        factor * (r[2] / r_mag) * fz,
+    ]
+}
+
+/// J2 oblateness perturbation as an [`Accel`] for the Cowell propagator.
+pub struct J2Perturbation {
+    /// Gravitational parameter `G · M` of the body.
+    pub mu: f64,
+    /// Dimensionless J2 coefficient.
+    pub j2: f64,
+    /// Equatorial radius of the body (m).
+    pub r_body: f64,
+}
+
+impl Accel for J2Perturbation {
+    fn acceleration(&self, _t: f64, r: [f64; 3], _v: [f64; 3]) -> [f64; 3] {
+        j2_acceleration(r, self.mu, self.j2, self.r_body)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::elements::{coe2rv, rv2coe, OrbitalElements};
+    use crate::propagation::propagate_cowell;
+    use crate::{EARTH_J2, EARTH_MASS, EARTH_RADIUS, G};
+
+    const MU: f64 = G * EARTH_MASS;
+
+    #[test]
+    fn test_nodal_regression_matches_secular_theory() {
+        // Secular nodal regression from propagating the J2 acceleration over one
+        // orbit must match the analytic rate
+        //   Ω̇ = −(3/2) · J2 · (R/p)² · n · cos(i).
+        let a = 7.0e6;
+        let ecc = 0.01;
+        let incl = 51.6_f64.to_radians();
+        let coe = OrbitalElements {
+            semi_major_axis: a,
+            eccentricity: ecc,
+            inclination: incl,
+            raan: 1.0,
+            arg_periapsis: 0.3,
+            true_anomaly: 0.0,
+        };
+        let (r0, v0) = coe2rv(MU, coe).unwrap();
+        let period = 2.0 * std::f64::consts::PI * (a.powi(3) / MU).sqrt();
+
+        let j2 = J2Perturbation {
+            mu: MU,
+            j2: EARTH_J2,
+            r_body: EARTH_RADIUS,
+        };
+        let states = propagate_cowell(MU, r0, v0, &[period], &[&j2], None);
+        let (rf, vf) = states[0];
+        let end = rv2coe(MU, rf, vf).unwrap();
+
+        let draan_per_orbit = end.raan - coe.raan;
+        let raan_rate = draan_per_orbit / period;
+
+        let p = a * (1.0 - ecc * ecc);
+        let n = (MU / a.powi(3)).sqrt();
+        let expected_rate = -1.5 * EARTH_J2 * (EARTH_RADIUS / p).powi(2) * n * incl.cos();
+
+        let error = ((raan_rate - expected_rate) / expected_rate).abs();
+        assert!(
+            error < 0.05,
+            "nodal regression rate mismatch: got {} rad/s, expected {} rad/s (error {:.3})",
+            raan_rate, expected_rate, error
+        );
+    }
+
+    #[test]
+    fn test_equatorial_acceleration_is_in_plane() {
+        // In the equatorial plane (z = 0) J2 produces no out-of-plane component.
+        let a = j2_acceleration([7.0e6, 0.0, 0.0], MU, EARTH_J2, EARTH_RADIUS);
+        assert_eq!(a[2], 0.0);
+        assert!(a[0] < 0.0, "should point inward on the +x axis");
+    }
+}
@@ -3,6 +3,15 @@
 //! Core physics and orbital mechanics calculations for space simulations.
 //! This library provides validated implementations of fundamental physics formulas.
 
+pub mod drag;
+pub mod elements;
+pub mod ephemerides;
+pub mod j2;
+pub mod propagation;
+pub mod tle;
+
+pub(crate) mod vec3;
+
 /// Gravitational constant in SI units (m³ kg⁻¹ s⁻²)
 pub const G: f64 = 6.67430e-11;
 
@@ -12,6 +21,12 @@ pub const EARTH_MASS: f64 = 5.972e24;
 /// Earth's radius in meters
 pub const EARTH_RADIUS: f64 = 6.371e6;
 
+/// Earth's second dynamic form factor (J2), the dominant oblateness term
+pub const EARTH_J2: f64 = 1.08263e-3;
+
+/// Earth's sidereal rotation rate in rad/s
+pub const OMEGA_EARTH: f64 = 7.2921159e-5;
+
 /// Calculate orbital velocity for a circular orbit
 /// 
 /// Formula: v = sqrt(G * M / r)
@@ -65,6 +80,124 @@ pub fn orbital_period(mass: f64, radius: f64) -> f64 {
     2.0 * std::f64::consts::PI * (radius.powi(3) / (G * mass)).sqrt()
 }
 
+/// Calculate orbital speed anywhere on an orbit via the vis-viva equation
+///
+/// Formula: v = sqrt(G * M * (2/r − 1/a))
+///
+/// Unlike [`orbital_velocity`], this works for elliptical orbits, where the
+/// speed varies with the current radius `r` while the semi-major axis `a`
+/// stays fixed. For a circular orbit `r = a` and it reduces to `sqrt(G·M/r)`.
+///
+/// # Arguments
+/// * `mass` - Mass of the central body (kg)
+/// * `r` - Current radius from center of mass (m)
+/// * `a` - Semi-major axis of the orbit (m)
+///
+/// # Returns
+/// Orbital speed in m/s
+///
+/// # Example
+/// ```
+/// use physics_validator::{vis_viva, orbital_velocity, EARTH_MASS, EARTH_RADIUS};
+///
+/// let r = EARTH_RADIUS + 400_000.0;
+/// // On a circular orbit the semi-major axis equals the radius.
+/// let v = vis_viva(EARTH_MASS, r, r);
+/// assert!((v - orbital_velocity(EARTH_MASS, r)).abs() < 1e-6);
+/// ```
+pub fn vis_viva(mass: f64, r: f64, a: f64) -> f64 {
+    (G * mass * (2.0 / r - 1.0 / a)).sqrt()
+}
+
+/// Calculate the speeds at apoapsis and periapsis of an elliptical orbit
+///
+/// The semi-major axis follows from the two apsis radii as `a = (r_a + r_p)/2`,
+/// and each speed is then evaluated with the vis-viva equation. Periapsis is
+/// the fastest point of the orbit and apoapsis the slowest.
+///
+/// # Arguments
+/// * `mass` - Mass of the central body (kg)
+/// * `r_periapsis` - Periapsis radius from center of mass (m)
+/// * `r_apoapsis` - Apoapsis radius from center of mass (m)
+///
+/// # Returns
+/// A `(v_apoapsis, v_periapsis)` pair of speeds in m/s
+///
+/// # Example
+/// ```
+/// use physics_validator::{apoapsis_periapsis_velocity, EARTH_MASS, EARTH_RADIUS};
+///
+/// let rp = EARTH_RADIUS + 200_000.0;
+/// let ra = EARTH_RADIUS + 35_786_000.0;
+/// let (v_apo, v_peri) = apoapsis_periapsis_velocity(EARTH_MASS, rp, ra);
+///
+/// // Periapsis is always faster than apoapsis.
+/// assert!(v_peri > v_apo);
+/// ```
+pub fn apoapsis_periapsis_velocity(mass: f64, r_periapsis: f64, r_apoapsis: f64) -> (f64, f64) {
+    let a = (r_apoapsis + r_periapsis) / 2.0;
+    (vis_viva(mass, r_apoapsis, a), vis_viva(mass, r_periapsis, a))
+}
+
+/// Result of a two-impulse Hohmann transfer between coplanar circular orbits.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HohmannTransfer {
+    /// Delta-v magnitude of the first (departure) burn (m/s).
+    pub delta_v1: f64,
+    /// Delta-v magnitude of the second (arrival) burn (m/s).
+    pub delta_v2: f64,
+    /// Time spent coasting along the transfer ellipse (s).
+    pub transfer_time: f64,
+}
+
+impl HohmannTransfer {
+    /// Total delta-v budget of the manoeuvre (`|Δv₁| + |Δv₂|`) in m/s.
+    pub fn total_delta_v(&self) -> f64 {
+        self.delta_v1 + self.delta_v2
+    }
+}
+
+/// Compute a Hohmann transfer between two coplanar circular orbits
+///
+/// The transfer ellipse has periapsis at `r1` and apoapsis at `r2` (or vice
+/// versa), hence semi-major axis `a = (r1 + r2)/2`. The first burn raises the
+/// circular speed at `r1` onto the ellipse and the second circularizes at `r2`;
+/// the coast lasts half the period of the transfer ellipse.
+///
+/// # Arguments
+/// * `mass` - Mass of the central body (kg)
+/// * `r1` - Radius of the starting circular orbit (m)
+/// * `r2` - Radius of the target circular orbit (m)
+///
+/// # Returns
+/// A [`HohmannTransfer`] with both burn magnitudes and the coast time.
+///
+/// # Example
+/// ```
+/// use physics_validator::{hohmann_transfer, EARTH_MASS, EARTH_RADIUS};
+///
+/// let leo = EARTH_RADIUS + 400_000.0;
+/// let geo = EARTH_RADIUS + 35_786_000.0;
+/// let transfer = hohmann_transfer(EARTH_MASS, leo, geo);
+///
+/// // LEO→GEO costs roughly 3.9 km/s in total.
+/// assert!((transfer.total_delta_v() - 3900.0).abs() < 200.0);
+/// ```
+pub fn hohmann_transfer(mass: f64, r1: f64, r2: f64) -> HohmannTransfer {
+    let a_transfer = (r1 + r2) / 2.0;
+
+    let v_circ1 = orbital_velocity(mass, r1);
+    let v_circ2 = orbital_velocity(mass, r2);
+    let v_transfer1 = vis_viva(mass, r1, a_transfer);
+    let v_transfer2 = vis_viva(mass, r2, a_transfer);
+
+    HohmannTransfer {
+        delta_v1: (v_transfer1 - v_circ1).abs(),
+        delta_v2: (v_circ2 - v_transfer2).abs(),
+        transfer_time: std::f64::consts::PI * (a_transfer.powi(3) / (G * mass)).sqrt(),
+    }
+}
+
 /// Calculate escape velocity from a gravitational body
 /// 
 /// Formula: v_esc = sqrt(2 * G * M / r)
@@ -140,6 +273,72 @@ pub fn gravitational_acceleration(mass: f64, distance: f64) -> f64 {
     G * mass / (distance * distance)
 }
 
+/// Calculate the gravitational acceleration vector at a position
+///
+/// Formula: a = −(G * M / |r|²) · (r / |r|)
+///
+/// The vector form of [`gravitational_acceleration`]: it keeps the direction
+/// (pointing back toward the central body) so callers running a simulation need
+/// not re-derive it. Everything stays in `f64` for precision, matching the
+/// `DVec3` port in the outfly `nature.rs`.
+///
+/// # Arguments
+/// * `r` - Position vector relative to the central body (m)
+/// * `mass` - Mass of the central body (kg)
+///
+/// # Returns
+/// Acceleration vector in m/s²
+///
+/// # Example
+/// ```
+/// use physics_validator::{gravitational_acceleration_vec, gravitational_acceleration, EARTH_MASS, EARTH_RADIUS};
+///
+/// let r = [EARTH_RADIUS, 0.0, 0.0];
+/// let a = gravitational_acceleration_vec(r, EARTH_MASS);
+///
+/// // Points inward along −x with the scalar magnitude.
+/// assert!(a[0] < 0.0);
+/// let mag = (a[0] * a[0] + a[1] * a[1] + a[2] * a[2]).sqrt();
+/// assert!((mag - gravitational_acceleration(EARTH_MASS, EARTH_RADIUS)).abs() < 1e-6);
+/// ```
+pub fn gravitational_acceleration_vec(r: [f64; 3], mass: f64) -> [f64; 3] {
+    let r_mag = vec3::norm(r);
+    vec3::scale(r, -G * mass / (r_mag * r_mag * r_mag))
+}
+
+/// Calculate a prograde circular-orbit velocity vector at a position
+///
+/// The speed is the scalar [`orbital_velocity`]; the direction is perpendicular
+/// to the radius vector, taken as `normalize([−r_z, 0, r_x])`, giving a
+/// prograde heading in the x–z plane. For `r` on the y-axis that direction is
+/// the zero vector, so the zero vector is returned.
+///
+/// # Arguments
+/// * `r` - Position vector relative to the central body (m)
+/// * `mass` - Mass of the central body (kg)
+///
+/// # Returns
+/// Velocity vector in m/s
+///
+/// # Example
+/// ```
+/// use physics_validator::{circular_orbit_velocity_vec, orbital_velocity, EARTH_MASS, EARTH_RADIUS};
+///
+/// let r = [EARTH_RADIUS + 400_000.0, 0.0, 0.0];
+/// let v = circular_orbit_velocity_vec(r, EARTH_MASS);
+///
+/// // Velocity is perpendicular to the radius and has the circular speed.
+/// let dot = r[0] * v[0] + r[1] * v[1] + r[2] * v[2];
+/// assert!(dot.abs() < 1e-3);
+/// let speed = (v[0] * v[0] + v[1] * v[1] + v[2] * v[2]).sqrt();
+/// assert!((speed - orbital_velocity(EARTH_MASS, r[0])).abs() < 1e-6);
+/// ```
+pub fn circular_orbit_velocity_vec(r: [f64; 3], mass: f64) -> [f64; 3] {
+    let r_mag = vec3::norm(r);
+    let perp = vec3::normalize([-r[2], 0.0, r[0]]);
+    vec3::scale(perp, (G * mass / r_mag).sqrt())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -250,6 +449,94 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_vis_viva_matches_circular_velocity() {
+        // On a circular orbit (r = a) vis-viva must reduce to sqrt(G·M/r).
+        let radius = EARTH_RADIUS + 400_000.0;
+        let v_vis_viva = vis_viva(EARTH_MASS, radius, radius);
+        let v_circular = orbital_velocity(EARTH_MASS, radius);
+
+        let error = relative_error(v_vis_viva, v_circular);
+        assert!(
+            error < TOLERANCE,
+            "vis-viva circular mismatch: got {} m/s, expected {} m/s (error: {:.9})",
+            v_vis_viva, v_circular, error
+        );
+    }
+
+    #[test]
+    fn test_apoapsis_slower_than_periapsis() {
+        let rp = EARTH_RADIUS + 200_000.0;
+        let ra = EARTH_RADIUS + 35_786_000.0;
+        let (v_apo, v_peri) = apoapsis_periapsis_velocity(EARTH_MASS, rp, ra);
+
+        assert!(
+            v_peri > v_apo,
+            "periapsis speed {} m/s should exceed apoapsis speed {} m/s",
+            v_peri, v_apo
+        );
+    }
+
+    #[test]
+    fn test_hohmann_leo_to_geo() {
+        // Canonical LEO (400 km) → GEO transfer: ~2.44 and ~1.47 km/s burns,
+        // ~5.26 hour coast. Values from Curtis, Orbital Mechanics.
+        let leo = EARTH_RADIUS + 400_000.0;
+        let geo = EARTH_RADIUS + 35_786_000.0;
+        let transfer = hohmann_transfer(EARTH_MASS, leo, geo);
+
+        assert!(
+            relative_error(transfer.delta_v1, 2440.0) < 0.02,
+            "Hohmann departure burn mismatch: got {} m/s",
+            transfer.delta_v1
+        );
+        assert!(
+            relative_error(transfer.delta_v2, 1470.0) < 0.02,
+            "Hohmann arrival burn mismatch: got {} m/s",
+            transfer.delta_v2
+        );
+        assert!(
+            relative_error(transfer.transfer_time, 5.26 * 3600.0) < 0.02,
+            "Hohmann transfer time mismatch: got {} s",
+            transfer.transfer_time
+        );
+    }
+
+    #[test]
+    fn test_gravitational_acceleration_vec_matches_scalar() {
+        let r = [EARTH_RADIUS, 0.0, 0.0];
+        let a = gravitational_acceleration_vec(r, EARTH_MASS);
+        let mag = (a[0] * a[0] + a[1] * a[1] + a[2] * a[2]).sqrt();
+        let scalar = gravitational_acceleration(EARTH_MASS, EARTH_RADIUS);
+
+        assert!(a[0] < 0.0, "acceleration should point inward, got {:?}", a);
+        let error = relative_error(mag, scalar);
+        assert!(
+            error < TOLERANCE,
+            "vector/scalar acceleration mismatch: |a| = {}, scalar = {} (error: {:.9})",
+            mag, scalar, error
+        );
+    }
+
+    #[test]
+    fn test_circular_orbit_velocity_vec_is_perpendicular() {
+        let r = [EARTH_RADIUS + 400_000.0, 0.0, 0.0];
+        let v = circular_orbit_velocity_vec(r, EARTH_MASS);
+
+        // Velocity perpendicular to radius (zero dot product).
+        let dot = r[0] * v[0] + r[1] * v[1] + r[2] * v[2];
+        assert!(dot.abs() < 1e-3, "velocity not perpendicular: r·v = {}", dot);
+
+        // Magnitude equals the scalar circular speed.
+        let speed = (v[0] * v[0] + v[1] * v[1] + v[2] * v[2]).sqrt();
+        let error = relative_error(speed, orbital_velocity(EARTH_MASS, r[0]));
+        assert!(
+            error < TOLERANCE,
+            "circular velocity magnitude mismatch (error: {:.9})",
+            error
+        );
+    }
+
     #[test]
     fn test_force_acceleration_relationship() {
         // F = m * a, so a = F / m
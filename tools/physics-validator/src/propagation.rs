@@ -0,0 +1,211 @@
+//! Numerical orbit propagation (Cowell's method).
+//!
+//! [`propagate_cowell`] integrates the perturbed two-body equation of motion
+//!
+//! ```text
+//! r̈ = −mu · r / |r|³ + Σ a_perturb
+//! ```
+//!
+//! with a fixed-step RK4 integrator, sampling the trajectory at each requested
+//! time of flight. Perturbing accelerations are supplied as [`Accel`] trait
+//! objects, so the J2, drag and third-body models in the sibling modules can be
+//! composed freely. An optional [`Event`] stops the integration early, mirroring
+//! the terminal-event behaviour of poliastro's `cowell`.
+
+use crate::vec3::{add, norm, scale};
+
+/// A position/velocity state, `(position, velocity)`.
+pub type State = ([f64; 3], [f64; 3]);
+
+/// Upper bound on the internal RK4 step size, in seconds.
+///
+/// Each interval between requested sample times is subdivided so that no single
+/// step exceeds this, trading a little speed for accuracy on long arcs.
+const MAX_STEP: f64 = 10.0;
+
+/// A perturbing acceleration that can be added to the two-body dynamics.
+///
+/// Implementors return the acceleration contribution (m/s²) at the given time
+/// and state. The two-body term is supplied by the propagator itself.
+pub trait Accel {
+    /// Perturbing acceleration at time `t` for the state `(r, v)`.
+    fn acceleration(&self, t: f64, r: [f64; 3], v: [f64; 3]) -> [f64; 3];
+}
+
+/// A terminal condition that halts propagation once satisfied.
+pub trait Event {
+    /// Return `true` when integration should stop at this state.
+    fn terminal(&self, t: f64, r: [f64; 3], v: [f64; 3]) -> bool;
+}
+
+/// Terminal event that fires when the orbital radius drops below `min_radius`.
+///
+/// Useful as a crude re-entry / line-of-sight cut-off: integration stops as soon
+/// as `|r|` falls under the threshold (e.g. the body radius plus a minimum
+/// altitude).
+pub struct AltitudeEvent {
+    /// Radius below which integration stops (m).
+    pub min_radius: f64,
+}
+
+impl Event for AltitudeEvent {
+    fn terminal(&self, _t: f64, r: [f64; 3], _v: [f64; 3]) -> bool {
+        norm(r) < self.min_radius
+    }
+}
+
+/// Total acceleration: two-body plus every perturbation.
+fn total_acceleration(
+    mu: f64,
+    t: f64,
+    r: [f64; 3],
+    v: [f64; 3],
+    perturbations: &[&dyn Accel],
+) -> [f64; 3] {
+    let r_mag = norm(r);
+    let mut a = scale(r, -mu / (r_mag * r_mag * r_mag));
+    for p in perturbations {
+        a = add(a, p.acceleration(t, r, v));
+    }
+    a
+}
+
+/// State derivative `(ṙ, v̇)` at time `t`.
+fn derivative(mu: f64, t: f64, state: State, perturbations: &[&dyn Accel]) -> State {
+    let (r, v) = state;
+    (v, total_acceleration(mu, t, r, v, perturbations))
+}
+
+/// Advance the state by one RK4 step of length `dt`.
+fn rk4_step(mu: f64, t: f64, state: State, dt: f64, perturbations: &[&dyn Accel]) -> State {
+    let k1 = derivative(mu, t, state, perturbations);
+    let s2 = add_state(state, scale_state(k1, dt / 2.0));
+    let k2 = derivative(mu, t + dt / 2.0, s2, perturbations);
+    let s3 = add_state(state, scale_state(k2, dt / 2.0));
+    let k3 = derivative(mu, t + dt / 2.0, s3, perturbations);
+    let s4 = add_state(state, scale_state(k3, dt));
+    let k4 = derivative(mu, t + dt, s4, perturbations);
+
+    // state + dt/6 · (k1 + 2k2 + 2k3 + k4)
+    let sum = add_state(
+        add_state(k1, scale_state(k2, 2.0)),
+        add_state(scale_state(k3, 2.0), k4),
+    );
+    add_state(state, scale_state(sum, dt / 6.0))
+}
+
+fn add_state(a: State, b: State) -> State {
+    (add(a.0, b.0), add(a.1, b.1))
+}
+
+fn scale_state(a: State, s: f64) -> State {
+    (scale(a.0, s), scale(a.1, s))
+}
+
+/// Propagate a two-body orbit with perturbations using Cowell's method.
+///
+/// # Arguments
+/// * `mu` - Gravitational parameter `G · M` of the central body
+/// * `r0` - Initial position (m)
+/// * `v0` - Initial velocity (m/s)
+/// * `tofs` - Times of flight to sample, in seconds, assumed ascending and
+///   non-negative
+/// * `perturbations` - Perturbing accelerations composed on top of two-body
+/// * `event` - Optional terminal event; when it fires, sampling stops and the
+///   returned vector holds only the states reached so far
+///
+/// # Returns
+/// One [`State`] per requested time of flight, in order. If `event` fires before
+/// a given time of flight is reached, that and all later samples are omitted.
+///
+/// # Example
+/// ```
+/// use physics_validator::propagation::propagate_cowell;
+/// use physics_validator::{EARTH_MASS, G};
+///
+/// let mu = G * EARTH_MASS;
+/// let r0 = [7.0e6, 0.0, 0.0];
+/// let v0 = [0.0, (mu / 7.0e6).sqrt(), 0.0];
+/// // Quarter of a circular orbit lands on the +y axis.
+/// let period = 2.0 * std::f64::consts::PI * (7.0e6_f64.powi(3) / mu).sqrt();
+/// let states = propagate_cowell(mu, r0, v0, &[period / 4.0], &[], None);
+/// let (r, _v) = states[0];
+/// assert!(r[1] > 0.0 && r[0].abs() < 1.0e5);
+/// ```
+pub fn propagate_cowell(
+    mu: f64,
+    r0: [f64; 3],
+    v0: [f64; 3],
+    tofs: &[f64],
+    perturbations: &[&dyn Accel],
+    event: Option<&dyn Event>,
+) -> Vec<State> {
+    let mut states = Vec::with_capacity(tofs.len());
+    let mut state = (r0, v0);
+    let mut t = 0.0;
+
+    for &tof in tofs {
+        // March from the current time up to the next sample time.
+        while t < tof {
+            let dt = (tof - t).min(MAX_STEP);
+            state = rk4_step(mu, t, state, dt, perturbations);
+            t += dt;
+            if let Some(ev) = event {
+                if ev.terminal(t, state.0, state.1) {
+                    return states;
+                }
+            }
+        }
+        states.push(state);
+    }
+
+    states
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{EARTH_MASS, G};
+
+    const MU: f64 = G * EARTH_MASS;
+
+    #[test]
+    fn test_circular_orbit_returns_to_start() {
+        // Propagating exactly one period should recover the initial state.
+        let r = 7.0e6;
+        let r0 = [r, 0.0, 0.0];
+        let v0 = [0.0, (MU / r).sqrt(), 0.0];
+        let period = 2.0 * std::f64::consts::PI * (r.powi(3) / MU).sqrt();
+
+        let states = propagate_cowell(MU, r0, v0, &[period], &[], None);
+        let (rf, _vf) = states[0];
+
+        assert!((rf[0] - r).abs() / r < 1e-4, "x drift: {}", rf[0]);
+        assert!(rf[1].abs() / r < 1e-4, "y drift: {}", rf[1]);
+    }
+
+    #[test]
+    fn test_quarter_orbit_position() {
+        let r = 7.0e6;
+        let r0 = [r, 0.0, 0.0];
+        let v0 = [0.0, (MU / r).sqrt(), 0.0];
+        let period = 2.0 * std::f64::consts::PI * (r.powi(3) / MU).sqrt();
+
+        let states = propagate_cowell(MU, r0, v0, &[period / 4.0], &[], None);
+        let (rf, _vf) = states[0];
+
+        assert!((rf[1] - r).abs() / r < 1e-3, "expected +y, got {:?}", rf);
+    }
+
+    #[test]
+    fn test_altitude_event_stops_early() {
+        // A suborbital drop toward the body should trip the altitude event and
+        // cut the sample list short.
+        let r0 = [7.0e6, 0.0, 0.0];
+        let v0 = [0.0, 100.0, 0.0]; // far too slow to stay in orbit
+        let event = AltitudeEvent { min_radius: 6.6e6 };
+
+        let states = propagate_cowell(MU, r0, v0, &[1000.0, 2000.0, 3000.0], &[], Some(&event));
+        assert!(states.len() < 3, "event should have stopped integration early");
+    }
+}
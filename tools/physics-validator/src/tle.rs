@@ -0,0 +1,484 @@
+//! Two-line element (TLE) parsing and SGP4 propagation.
+//!
+//! [`Tle::parse`] decodes a NORAD two-line element set — validating the line
+//! checksums, then reading the epoch, mean motion, eccentricity and orientation
+//! angles —
+//! and [`sgp4_propagate`] advances it with the near-Earth SGP4 model from
+//! Spacetrack Report #3 (WGS-72 constants), returning a TEME-frame state in km
+//! and km/s. This lets the crate ingest real Celestrak / Space-Track catalogs
+//! rather than only idealized two-body cases.
+//!
+//! Deep-space objects (orbital period ≥ 225 min, which would require SDP4) are
+//! outside the scope of this near-Earth implementation.
+
+use std::error::Error;
+use std::fmt;
+
+use std::f64::consts::PI;
+
+const TWO_PI: f64 = 2.0 * PI;
+const DEG2RAD: f64 = PI / 180.0;
+const MIN_PER_DAY: f64 = 1440.0;
+
+// WGS-72 gravity model constants used by SGP4.
+const XKMPER: f64 = 6378.135; // Earth equatorial radius (km)
+const XKE: f64 = 0.0743669161; // sqrt(GM) in (er^1.5 / min)
+const CK2: f64 = 5.413080e-4; // ½ J2 aE²
+const CK4: f64 = 0.62098875e-6; // −⅜ J4 aE⁴
+const XJ3: f64 = -0.253881e-5; // J3
+const QOMS2T: f64 = 1.88027916e-9;
+const S: f64 = 1.01222928;
+const E6A: f64 = 1.0e-6;
+const TOTHRD: f64 = 2.0 / 3.0;
+const AE: f64 = 1.0;
+
+/// Errors produced while parsing a TLE.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TleError {
+    /// A line was the wrong length or a field could not be read as a number.
+    InvalidFormat,
+    /// A line's checksum digit did not match the computed modulo-10 checksum.
+    InvalidChecksum,
+}
+
+impl fmt::Display for TleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TleError::InvalidFormat => write!(f, "malformed TLE line"),
+            TleError::InvalidChecksum => write!(f, "TLE line checksum mismatch"),
+        }
+    }
+}
+
+impl Error for TleError {}
+
+/// A parsed two-line element set.
+///
+/// Angles are stored in radians and the mean motion in radians per minute, ready
+/// for [`sgp4_propagate`]; the raw epoch is kept as the full year plus the
+/// fractional day of year.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Tle {
+    /// NORAD catalog number.
+    pub satellite_number: u32,
+    /// Four-digit epoch year.
+    pub epoch_year: i32,
+    /// Fractional day of year of the epoch.
+    pub epoch_day: f64,
+    /// First time derivative of the mean motion divided by two, as printed in
+    /// the TLE (rev/day²).
+    pub mean_motion_dot: f64,
+    /// Second time derivative of the mean motion divided by six, as printed in
+    /// the TLE (rev/day³).
+    pub mean_motion_ddot: f64,
+    /// B* drag term (1/earth radii).
+    pub bstar: f64,
+    /// Inclination (rad).
+    pub inclination: f64,
+    /// Right ascension of the ascending node (rad).
+    pub raan: f64,
+    /// Eccentricity (dimensionless).
+    pub eccentricity: f64,
+    /// Argument of perigee (rad).
+    pub arg_perigee: f64,
+    /// Mean anomaly (rad).
+    pub mean_anomaly: f64,
+    /// Mean motion (rad/min).
+    pub mean_motion: f64,
+}
+
+/// Read a fixed-column slice and parse it as `f64`, trimming whitespace.
+fn field_f64(line: &str, range: std::ops::Range<usize>) -> Result<f64, TleError> {
+    line.get(range)
+        .ok_or(TleError::InvalidFormat)?
+        .trim()
+        .parse::<f64>()
+        .map_err(|_| TleError::InvalidFormat)
+}
+
+/// Decode an "assumed decimal point" exponential field such as `-11606-4`,
+/// meaning `−0.11606 · 10⁻⁴`.
+fn decode_exp_field(raw: &str) -> Result<f64, TleError> {
+    let raw = raw.trim();
+    if raw.is_empty() {
+        return Ok(0.0);
+    }
+    // Split the mantissa sign from the rest.
+    let (sign, rest) = match raw.strip_prefix('-') {
+        Some(r) => (-1.0, r),
+        None => (1.0, raw.strip_prefix('+').unwrap_or(raw)),
+    };
+    // The exponent is introduced by the last sign character.
+    let split = rest
+        .rfind(['+', '-'])
+        .ok_or(TleError::InvalidFormat)?;
+    let mantissa: f64 = format!("0.{}", &rest[..split])
+        .parse()
+        .map_err(|_| TleError::InvalidFormat)?;
+    let exponent: i32 = rest[split..].parse().map_err(|_| TleError::InvalidFormat)?;
+    Ok(sign * mantissa * 10f64.powi(exponent))
+}
+
+/// Compute the modulo-10 checksum of a line (digits summed, `-` counts as 1).
+fn checksum(line: &str) -> u32 {
+    line.chars()
+        .take(68)
+        .map(|c| match c {
+            '0'..='9' => c.to_digit(10).unwrap(),
+            '-' => 1,
+            _ => 0,
+        })
+        .sum::<u32>()
+        % 10
+}
+
+fn verify_checksum(line: &str) -> Result<(), TleError> {
+    let expected = line
+        .chars()
+        .nth(68)
+        .and_then(|c| c.to_digit(10))
+        .ok_or(TleError::InvalidFormat)?;
+    if checksum(line) == expected {
+        Ok(())
+    } else {
+        Err(TleError::InvalidChecksum)
+    }
+}
+
+impl Tle {
+    /// Parse a TLE from its two data lines (the optional title line is ignored).
+    ///
+    /// Both lines are checksum-validated before any field is trusted.
+    pub fn parse(line1: &str, line2: &str) -> Result<Tle, TleError> {
+        if line1.len() < 69 || line2.len() < 69 {
+            return Err(TleError::InvalidFormat);
+        }
+        verify_checksum(line1)?;
+        verify_checksum(line2)?;
+
+        let satellite_number = line1
+            .get(2..7)
+            .ok_or(TleError::InvalidFormat)?
+            .trim()
+            .parse::<u32>()
+            .map_err(|_| TleError::InvalidFormat)?;
+
+        let epoch_yy = line1
+            .get(18..20)
+            .ok_or(TleError::InvalidFormat)?
+            .trim()
+            .parse::<i32>()
+            .map_err(|_| TleError::InvalidFormat)?;
+        let epoch_year = if epoch_yy < 57 { 2000 + epoch_yy } else { 1900 + epoch_yy };
+        let epoch_day = field_f64(line1, 20..32)?;
+
+        let mean_motion_dot = field_f64(line1, 33..43)?;
+        let mean_motion_ddot = decode_exp_field(line1.get(44..52).ok_or(TleError::InvalidFormat)?)?;
+        let bstar = decode_exp_field(line1.get(53..61).ok_or(TleError::InvalidFormat)?)?;
+
+        let inclination = field_f64(line2, 8..16)? * DEG2RAD;
+        let raan = field_f64(line2, 17..25)? * DEG2RAD;
+        let eccentricity = format!("0.{}", line2.get(26..33).ok_or(TleError::InvalidFormat)?.trim())
+            .parse::<f64>()
+            .map_err(|_| TleError::InvalidFormat)?;
+        let arg_perigee = field_f64(line2, 34..42)? * DEG2RAD;
+        let mean_anomaly = field_f64(line2, 43..51)? * DEG2RAD;
+        let mean_motion_rev_day = field_f64(line2, 52..63)?;
+        let mean_motion = mean_motion_rev_day * TWO_PI / MIN_PER_DAY;
+
+        Ok(Tle {
+            satellite_number,
+            epoch_year,
+            epoch_day,
+            mean_motion_dot,
+            mean_motion_ddot,
+            bstar,
+            inclination,
+            raan,
+            eccentricity,
+            arg_perigee,
+            mean_anomaly,
+            mean_motion,
+        })
+    }
+}
+
+/// Propagate a TLE with the near-Earth SGP4 model.
+///
+/// # Arguments
+/// * `tle` - The parsed element set
+/// * `minutes_since_epoch` - Time past the TLE epoch, in minutes
+///
+/// # Returns
+/// A `(position, velocity)` pair in the TEME frame, position in km and velocity
+/// in km/s.
+pub fn sgp4_propagate(tle: &Tle, minutes_since_epoch: f64) -> ([f64; 3], [f64; 3]) {
+    let xno = tle.mean_motion;
+    let xincl = tle.inclination;
+    let xnodeo = tle.raan;
+    let eo = tle.eccentricity;
+    let omegao = tle.arg_perigee;
+    let xmo = tle.mean_anomaly;
+    let bstar = tle.bstar;
+
+    // --- Recover original mean motion and semi-major axis. ---
+    let a1 = (XKE / xno).powf(TOTHRD);
+    let cosio = xincl.cos();
+    let theta2 = cosio * cosio;
+    let x3thm1 = 3.0 * theta2 - 1.0;
+    let eosq = eo * eo;
+    let betao2 = 1.0 - eosq;
+    let betao = betao2.sqrt();
+    let del1 = 1.5 * CK2 * x3thm1 / (a1 * a1 * betao * betao2);
+    let ao = a1 * (1.0 - del1 * (0.5 * TOTHRD + del1 * (1.0 + 134.0 / 81.0 * del1)));
+    let delo = 1.5 * CK2 * x3thm1 / (ao * ao * betao * betao2);
+    let xnodp = xno / (1.0 + delo);
+    let aodp = ao / (1.0 - delo);
+
+    // Low-perigee handling adjusts the atmospheric constants.
+    let perige = (aodp * (1.0 - eo) - AE) * XKMPER;
+    let mut s4 = S;
+    let mut qoms24 = QOMS2T;
+    if perige < 156.0 {
+        let mut s_temp = perige - 78.0;
+        if perige < 98.0 {
+            s_temp = 20.0;
+        }
+        qoms24 = ((120.0 - s_temp) * AE / XKMPER).powi(4);
+        s4 = s_temp / XKMPER + AE;
+    }
+
+    let pinvsq = 1.0 / (aodp * aodp * betao2 * betao2);
+    let tsi = 1.0 / (aodp - s4);
+    let eta = aodp * eo * tsi;
+    let etasq = eta * eta;
+    let eeta = eo * eta;
+    let psisq = (1.0 - etasq).abs();
+    let coef = qoms24 * tsi.powi(4);
+    let coef1 = coef / psisq.powf(3.5);
+    let c2 = coef1
+        * xnodp
+        * (aodp * (1.0 + 1.5 * etasq + eeta * (4.0 + etasq))
+            + 0.75 * CK2 * tsi / psisq * x3thm1 * (8.0 + 3.0 * etasq * (8.0 + etasq)));
+    let c1 = bstar * c2;
+    let sinio = xincl.sin();
+    let a3ovk2 = -XJ3 / CK2 * AE.powi(3);
+    let c3 = coef * tsi * a3ovk2 * xnodp * AE * sinio / eo;
+    let x1mth2 = 1.0 - theta2;
+    let c4 = 2.0
+        * xnodp
+        * coef1
+        * aodp
+        * betao2
+        * (eta * (2.0 + 0.5 * etasq) + eo * (0.5 + 2.0 * etasq)
+            - 2.0 * CK2 * tsi / (aodp * psisq)
+                * (-3.0 * x3thm1 * (1.0 - 2.0 * eeta + etasq * (1.5 - 0.5 * eeta))
+                    + 0.75 * x1mth2 * (2.0 * etasq - eeta * (1.0 + etasq)) * (2.0 * omegao).cos()));
+    let c5 = 2.0 * coef1 * aodp * betao2 * (1.0 + 2.75 * (etasq + eeta) + eeta * etasq);
+
+    let theta4 = theta2 * theta2;
+    let temp1 = 3.0 * CK2 * pinvsq * xnodp;
+    let temp2 = temp1 * CK2 * pinvsq;
+    let temp3 = 1.25 * CK4 * pinvsq * pinvsq * xnodp;
+    let xmdot = xnodp
+        + 0.5 * temp1 * betao * x3thm1
+        + 0.0625 * temp2 * betao * (13.0 - 78.0 * theta2 + 137.0 * theta4);
+    let x1m5th = 1.0 - 5.0 * theta2;
+    let omgdot = -0.5 * temp1 * x1m5th
+        + 0.0625 * temp2 * (7.0 - 114.0 * theta2 + 395.0 * theta4)
+        + temp3 * (3.0 - 36.0 * theta2 + 49.0 * theta4);
+    let xhdot1 = -temp1 * cosio;
+    let xnodot = xhdot1
+        + (0.5 * temp2 * (4.0 - 19.0 * theta2) + 2.0 * temp3 * (3.0 - 7.0 * theta2)) * cosio;
+    let omgcof = bstar * c3 * omegao.cos();
+    let xmcof = -TOTHRD * coef * bstar * AE / eeta;
+    let xnodcf = 3.5 * betao2 * xhdot1 * c1;
+    let t2cof = 1.5 * c1;
+    let xlcof = 0.125 * a3ovk2 * sinio * (3.0 + 5.0 * cosio) / (1.0 + cosio);
+    let aycof = 0.25 * a3ovk2 * sinio;
+    let delmo = (1.0 + eta * xmo.cos()).powi(3);
+    let sinmo = xmo.sin();
+    let x7thm1 = 7.0 * theta2 - 1.0;
+
+    let isimp = (aodp * (1.0 - eo) / AE) < (220.0 / XKMPER + AE);
+
+    // Secular-drag polynomial coefficients (only for non-simplified path).
+    let (mut d2, mut d3, mut d4) = (0.0, 0.0, 0.0);
+    let (mut t3cof, mut t4cof, mut t5cof) = (0.0, 0.0, 0.0);
+    if !isimp {
+        let c1sq = c1 * c1;
+        d2 = 4.0 * aodp * tsi * c1sq;
+        let temp = d2 * tsi * c1 / 3.0;
+        d3 = (17.0 * aodp + s4) * temp;
+        d4 = 0.5 * temp * aodp * tsi * (221.0 * aodp + 31.0 * s4) * c1;
+        t3cof = d2 + 2.0 * c1sq;
+        t4cof = 0.25 * (3.0 * d3 + c1 * (12.0 * d2 + 10.0 * c1sq));
+        t5cof = 0.2 * (3.0 * d4 + 12.0 * c1 * d3 + 6.0 * d2 * d2 + 15.0 * c1sq * (2.0 * d2 + c1sq));
+    }
+
+    // --- Secular update. ---
+    let t = minutes_since_epoch;
+    let xmdf = xmo + xmdot * t;
+    let omgadf = omegao + omgdot * t;
+    let xnoddf = xnodeo + xnodot * t;
+    let tsq = t * t;
+    let xnode = xnoddf + xnodcf * tsq;
+    let mut tempa = 1.0 - c1 * t;
+    let mut tempe = bstar * c4 * t;
+    let mut templ = t2cof * tsq;
+
+    let (mut omega, mut xmp) = (omgadf, xmdf);
+    if !isimp {
+        let delomg = omgcof * t;
+        let delm = xmcof * ((1.0 + eta * xmdf.cos()).powi(3) - delmo);
+        let temp = delomg + delm;
+        xmp = xmdf + temp;
+        omega = omgadf - temp;
+        let tcube = tsq * t;
+        let tfour = t * tcube;
+        tempa = tempa - d2 * tsq - d3 * tcube - d4 * tfour;
+        tempe += bstar * c5 * (xmp.sin() - sinmo);
+        templ += t3cof * tcube + tfour * (t4cof + t * t5cof);
+    }
+
+    let a = aodp * tempa * tempa;
+    let e = eo - tempe;
+    let xl = xmp + omega + xnode + xnodp * templ;
+    let beta = (1.0 - e * e).sqrt();
+    let xn = XKE / a.powf(1.5);
+
+    // --- Long-period periodics. ---
+    let axn = e * omega.cos();
+    let temp = 1.0 / (a * beta * beta);
+    let xll = temp * xlcof * axn;
+    let aynl = temp * aycof;
+    let xlt = xl + xll;
+    let ayn = e * omega.sin() + aynl;
+
+    // --- Solve Kepler's equation for (E + ω). ---
+    let capu = (xlt - xnode).rem_euclid(TWO_PI);
+    let mut epw = capu;
+    for _ in 0..10 {
+        let sinepw = epw.sin();
+        let cosepw = epw.cos();
+        let temp3 = axn * sinepw;
+        let temp4 = ayn * cosepw;
+        let temp5 = axn * cosepw;
+        let temp6 = ayn * sinepw;
+        let next = (capu - temp4 + temp3 - epw) / (1.0 - temp5 - temp6) + epw;
+        if (next - epw).abs() <= E6A {
+            epw = next;
+            break;
+        }
+        epw = next;
+    }
+
+    // --- Short-period preliminary quantities. ---
+    let sinepw = epw.sin();
+    let cosepw = epw.cos();
+    let ecose = axn * cosepw + ayn * sinepw;
+    let esine = axn * sinepw - ayn * cosepw;
+    let elsq = axn * axn + ayn * ayn;
+    let temp = 1.0 - elsq;
+    let pl = a * temp;
+    let r = a * (1.0 - ecose);
+    let temp1 = 1.0 / r;
+    let rdot = XKE * a.sqrt() * esine * temp1;
+    let rfdot = XKE * pl.sqrt() * temp1;
+    let temp2 = a * temp1;
+    let betal = temp.sqrt();
+    let temp3 = 1.0 / (1.0 + betal);
+    let cosu = temp2 * (cosepw - axn + ayn * esine * temp3);
+    let sinu = temp2 * (sinepw - ayn - axn * esine * temp3);
+    let u = sinu.atan2(cosu);
+    let sin2u = 2.0 * sinu * cosu;
+    let cos2u = 2.0 * cosu * cosu - 1.0;
+    let temp = 1.0 / pl;
+    let temp1 = CK2 * temp;
+    let temp2 = temp1 * temp;
+
+    // --- Update for short-period periodics. ---
+    let rk = r * (1.0 - 1.5 * temp2 * betal * x3thm1) + 0.5 * temp1 * x1mth2 * cos2u;
+    let uk = u - 0.25 * temp2 * x7thm1 * sin2u;
+    let xnodek = xnode + 1.5 * temp2 * cosio * sin2u;
+    let xinck = xincl + 1.5 * temp2 * cosio * sinio * cos2u;
+    let rdotk = rdot - xn * temp1 * x1mth2 * sin2u;
+    let rfdotk = rfdot + xn * temp1 * (x1mth2 * cos2u + 1.5 * x3thm1);
+
+    // --- Orientation vectors and state. ---
+    let sinuk = uk.sin();
+    let cosuk = uk.cos();
+    let sinik = xinck.sin();
+    let cosik = xinck.cos();
+    let sinnok = xnodek.sin();
+    let cosnok = xnodek.cos();
+    let xmx = -sinnok * cosik;
+    let xmy = cosnok * cosik;
+    let ux = xmx * sinuk + cosnok * cosuk;
+    let uy = xmy * sinuk + sinnok * cosuk;
+    let uz = sinik * sinuk;
+    let vx = xmx * cosuk - cosnok * sinuk;
+    let vy = xmy * cosuk - sinnok * sinuk;
+    let vz = sinik * cosuk;
+
+    let position = [rk * ux * XKMPER, rk * uy * XKMPER, rk * uz * XKMPER];
+    let vfac = XKMPER / 60.0; // earth-radii/min → km/s
+    let velocity = [
+        (rdotk * ux + rfdotk * vx) * vfac,
+        (rdotk * uy + rfdotk * vy) * vfac,
+        (rdotk * uz + rfdotk * vz) * vfac,
+    ];
+
+    (position, velocity)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{orbital_velocity, EARTH_MASS, EARTH_RADIUS};
+
+    // Canonical ISS test element set (Vallado).
+    const ISS_L1: &str =
+        "1 25544U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0  2927";
+    const ISS_L2: &str =
+        "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
+
+    fn norm(v: [f64; 3]) -> f64 {
+        (v[0] * v[0] + v[1] * v[1] + v[2] * v[2]).sqrt()
+    }
+
+    #[test]
+    fn test_parse_iss_fields() {
+        let tle = Tle::parse(ISS_L1, ISS_L2).unwrap();
+        assert_eq!(tle.satellite_number, 25544);
+        assert_eq!(tle.epoch_year, 2008);
+        assert!((tle.inclination - 51.6416 * DEG2RAD).abs() < 1e-9);
+        assert!((tle.eccentricity - 0.0006703).abs() < 1e-9);
+        assert!(tle.bstar.abs() > 0.0);
+    }
+
+    #[test]
+    fn test_bad_checksum_is_rejected() {
+        // Corrupt the final checksum digit of line 1.
+        let bad = format!("{}0", &ISS_L1[..68]);
+        assert_eq!(Tle::parse(&bad, ISS_L2), Err(TleError::InvalidChecksum));
+    }
+
+    #[test]
+    fn test_iss_speed_matches_two_body_estimate() {
+        // At epoch the SGP4 speed should agree with the simple circular-orbit
+        // estimate at the same radius, to within a loose tolerance.
+        let tle = Tle::parse(ISS_L1, ISS_L2).unwrap();
+        let (r, v) = sgp4_propagate(&tle, 0.0);
+        let r_m = norm(r) * 1000.0;
+        let v_m = norm(v) * 1000.0;
+
+        let v_circular = orbital_velocity(EARTH_MASS, r_m);
+        let error = ((v_m - v_circular) / v_circular).abs();
+        assert!(error < 0.05, "ISS speed {} m/s vs estimate {} m/s", v_m, v_circular);
+
+        // Sanity: the altitude is a few hundred km.
+        let altitude = r_m - EARTH_RADIUS;
+        assert!((150_000.0..=600_000.0).contains(&altitude), "altitude {} m", altitude);
+    }
+}
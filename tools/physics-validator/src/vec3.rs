@@ -0,0 +1,52 @@
+//! Minimal 3D vector helpers over `[f64; 3]`
+//!
+//! The crate keeps everything in fixed-size `f64` arrays rather than pulling in
+//! a linear-algebra dependency, so these small free functions are shared by the
+//! orbital-element conversions, the perturbation models, and the propagator.
+
+/// Vector addition `a + b`.
+pub(crate) fn add(a: [f64; 3], b: [f64; 3]) -> [f64; 3] {
+    [a[0] + b[0], a[1] + b[1], a[2] + b[2]]
+}
+
+/// Vector subtraction `a − b`.
+pub(crate) fn sub(a: [f64; 3], b: [f64; 3]) -> [f64; 3] {
+    [a[0] - b[0], a[1] - b[1], a[2] - b[2]]
+}
+
+/// Scalar multiple `s · a`.
+pub(crate) fn scale(a: [f64; 3], s: f64) -> [f64; 3] {
+    [a[0] * s, a[1] * s, a[2] * s]
+}
+
+/// Dot product `a · b`.
+pub(crate) fn dot(a: [f64; 3], b: [f64; 3]) -> f64 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+/// Cross product `a × b`.
+pub(crate) fn cross(a: [f64; 3], b: [f64; 3]) -> [f64; 3] {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
+
+/// Euclidean norm `|a|`.
+pub(crate) fn norm(a: [f64; 3]) -> f64 {
+    dot(a, a).sqrt()
+}
+
+/// Unit vector `a / |a|`.
+///
+/// Returns the zero vector when `a` is (numerically) the zero vector, leaving
+/// the caller to treat that degenerate case as it sees fit.
+pub(crate) fn normalize(a: [f64; 3]) -> [f64; 3] {
+    let n = norm(a);
+    if n == 0.0 {
+        [0.0, 0.0, 0.0]
+    } else {
+        scale(a, 1.0 / n)
+    }
+}